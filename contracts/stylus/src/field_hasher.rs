@@ -0,0 +1,29 @@
+//! Field Hasher Abstraction
+//!
+//! Generalizes Merkle and FRI verification over any 2-to-1 / n-to-1 field
+//! hash, so proofs built with a different Poseidon arity (e.g. arkworks'
+//! `t=4` trees) or a cheaper non-ZK hash can verify through the same code
+//! path as the current BN254 Poseidon.
+
+use alloy_primitives::U256;
+
+use crate::poseidon::PoseidonHasher;
+
+/// A field hash usable for Merkle tree and FRI commitments.
+pub trait FieldHasher {
+    /// Hash two field elements into one (binary tree nodes).
+    fn hash_two(&self, a: U256, b: U256) -> U256;
+
+    /// Hash an arbitrary number of field elements into one.
+    fn hash_n(&self, inputs: &[U256]) -> U256;
+}
+
+impl FieldHasher for PoseidonHasher {
+    fn hash_two(&self, a: U256, b: U256) -> U256 {
+        PoseidonHasher::hash_two(a, b)
+    }
+
+    fn hash_n(&self, inputs: &[U256]) -> U256 {
+        PoseidonHasher::hash_n(inputs)
+    }
+}
@@ -0,0 +1,417 @@
+//! FRI (Fast Reed-Solomon IOP of Proximity) Low-Degree Test
+//!
+//! Verifies that a committed polynomial is close to a low-degree polynomial.
+//! The prover commits to a sequence of folded polynomials `f_0, f_1, ..., f_n`
+//! (one Merkle root per layer) where each `f_{i+1}` is derived from `f_i` by
+//! folding pairs of evaluations with a Fiat-Shamir challenge `beta_i`. The
+//! verifier re-derives every `beta_i` from the layer roots, then for each
+//! query checks that the folding equation holds all the way down to a final,
+//! constant layer. Query positions are themselves derived from the
+//! transcript rather than taken from the proof, so a prover cannot choose
+//! which positions to open.
+
+use alloc::vec::Vec;
+use alloy_primitives::U256;
+
+use crate::field_hasher::FieldHasher;
+use crate::merkle::MerkleVerifier;
+use crate::poseidon::domain::{self, MAX_LOG2_DOMAIN_SIZE};
+use crate::poseidon::field::BN254Field;
+use crate::transcript::Transcript;
+
+/// A single Merkle-authenticated evaluation within a FRI layer.
+#[derive(Clone)]
+pub struct FriOpening {
+    /// The claimed evaluation `f_i(x)`.
+    pub value: U256,
+    /// Sibling hashes from the leaf up to the layer root.
+    pub path: Vec<U256>,
+    /// Position indicator for each level (false=left, true=right).
+    pub indices: Vec<bool>,
+}
+
+/// One query's openings across every folding layer.
+#[derive(Clone)]
+pub struct FriQuery {
+    /// Index into the first layer's evaluation domain. Must match the index
+    /// independently derived from the transcript, so a prover cannot choose
+    /// which positions to open.
+    pub index: u64,
+    /// `(f_i(x), f_i(-x))` openings for every committed layer `i`.
+    pub layers: Vec<(FriOpening, FriOpening)>,
+}
+
+/// A full FRI proximity proof.
+#[derive(Clone)]
+pub struct FriProof {
+    /// One Merkle root per folding layer.
+    pub layer_roots: Vec<U256>,
+    /// Openings for every random query.
+    pub queries: Vec<FriQuery>,
+    /// Value of the final, constant layer.
+    pub final_value: U256,
+}
+
+/// FRI low-degree test verifier.
+pub struct FriVerifier;
+
+impl FriVerifier {
+    /// Verify that the polynomial committed to by `proof` is close to a
+    /// polynomial of degree `<= final_degree`, over an evaluation domain of
+    /// size `domain_size` (must be a power of two), using `num_queries`
+    /// query points.
+    ///
+    /// # Arguments
+    /// * `proof` - The FRI proof, with one Merkle root per folding layer and
+    ///   one [`FriQuery`] per query index
+    /// * `domain_size` - Size of the first layer's evaluation domain (power of two)
+    /// * `num_queries` - Expected number of queries in the proof
+    /// * `final_degree` - Maximum allowed degree of the final, constant layer
+    ///
+    /// # Returns
+    /// `true` if every query opens the position the transcript independently
+    /// derives for it and is consistent with the claimed folding, and the
+    /// domain shrinks enough to justify `final_degree`.
+    pub fn verify<H: FieldHasher>(
+        proof: &FriProof,
+        domain_size: u64,
+        num_queries: usize,
+        final_degree: usize,
+        hasher: &H,
+    ) -> bool {
+        if domain_size == 0 || !domain_size.is_power_of_two() {
+            return false;
+        }
+        if domain_size.trailing_zeros() > MAX_LOG2_DOMAIN_SIZE {
+            return false;
+        }
+        if proof.layer_roots.is_empty() || proof.queries.len() != num_queries {
+            return false;
+        }
+
+        let num_layers = proof.layer_roots.len();
+        if num_layers as u32 > MAX_LOG2_DOMAIN_SIZE {
+            return false;
+        }
+
+        // The final domain must still be larger than the claimed degree -
+        // otherwise a constant layer is not a meaningful low-degree bound.
+        let final_domain_size = domain_size >> num_layers as u32;
+        if final_domain_size == 0 || final_degree as u64 >= final_domain_size {
+            return false;
+        }
+
+        // Commit phase: derive one folding challenge per layer, then one
+        // query index per query, all via Fiat-Shamir over the layer roots
+        // and the final value.
+        let (betas, query_indices) = Self::derive_challenges(
+            &proof.layer_roots,
+            proof.final_value,
+            num_queries,
+            domain_size,
+        );
+
+        let inv2 = match BN254Field::inv(U256::from(2u64)) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        // Query phase.
+        for (query, expected_index) in proof.queries.iter().zip(query_indices.iter()) {
+            if query.index != *expected_index || query.layers.len() != num_layers {
+                return false;
+            }
+
+            let mut index = query.index;
+            let mut size = domain_size;
+
+            for (layer_idx, (opening_pos, opening_neg)) in query.layers.iter().enumerate() {
+                let root = proof.layer_roots[layer_idx];
+                let half = size / 2;
+                let neg_index = (index + half) % size;
+
+                if Self::path_index(&opening_pos.indices) != index
+                    || Self::path_index(&opening_neg.indices) != neg_index
+                {
+                    return false;
+                }
+                if !MerkleVerifier::verify(
+                    root,
+                    opening_pos.value,
+                    &opening_pos.path,
+                    &opening_pos.indices,
+                    hasher,
+                ) {
+                    return false;
+                }
+                if !MerkleVerifier::verify(
+                    root,
+                    opening_neg.value,
+                    &opening_neg.path,
+                    &opening_neg.indices,
+                    hasher,
+                ) {
+                    return false;
+                }
+
+                let x = Self::domain_point(size, index);
+                let two_x = BN254Field::mul(U256::from(2u64), x);
+                let inv_two_x = match BN254Field::inv(two_x) {
+                    Some(v) => v,
+                    None => return false,
+                };
+
+                let sum = BN254Field::mul(
+                    BN254Field::add(opening_pos.value, opening_neg.value),
+                    inv2,
+                );
+                let diff = BN254Field::mul(
+                    BN254Field::sub(opening_pos.value, opening_neg.value),
+                    inv_two_x,
+                );
+                let folded = BN254Field::add(sum, BN254Field::mul(betas[layer_idx], diff));
+
+                let is_last_layer = layer_idx + 1 == num_layers;
+                if is_last_layer {
+                    if folded != proof.final_value {
+                        return false;
+                    }
+                } else {
+                    let (next_pos, _) = &query.layers[layer_idx + 1];
+                    if next_pos.value != folded {
+                        return false;
+                    }
+                }
+
+                size = half;
+                index %= half;
+            }
+        }
+
+        true
+    }
+
+    /// Fiat-Shamir: derive one folding challenge per layer, absorbing each
+    /// layer root into the transcript before squeezing its challenge so
+    /// `beta_i` is bound to every root up to and including layer `i`. Then,
+    /// after absorbing `final_value`, derive one query index per query the
+    /// same way. Binding indices to the full commitment (every layer root
+    /// plus the final value) stops a prover from picking which positions to
+    /// open before it knows what those positions will be.
+    fn derive_challenges(
+        layer_roots: &[U256],
+        final_value: U256,
+        num_queries: usize,
+        domain_size: u64,
+    ) -> (Vec<U256>, Vec<u64>) {
+        let mut transcript = Transcript::new();
+        let mut betas = Vec::with_capacity(layer_roots.len());
+
+        for root in layer_roots {
+            transcript.absorb(*root);
+            betas.push(transcript.squeeze_challenge());
+        }
+
+        transcript.absorb(final_value);
+
+        let mut indices = Vec::with_capacity(num_queries);
+        for _ in 0..num_queries {
+            let challenge = transcript.squeeze_challenge();
+            indices.push(Self::index_from_challenge(challenge, domain_size));
+        }
+
+        (betas, indices)
+    }
+
+    /// Reduce a transcript challenge down to a query position in
+    /// `[0, domain_size)`.
+    fn index_from_challenge(challenge: U256, domain_size: u64) -> u64 {
+        challenge.as_limbs()[0] % domain_size
+    }
+
+    /// Recover the integer position a Merkle path encodes, reading bits from
+    /// leaf (bit 0) to root, matching [`MerkleVerifier`]'s indices convention.
+    fn path_index(indices: &[bool]) -> u64 {
+        let mut index = 0u64;
+        for (level, is_right) in indices.iter().enumerate() {
+            if *is_right {
+                index |= 1 << level;
+            }
+        }
+        index
+    }
+
+    /// The evaluation domain point `g^index`, where `g` generates the
+    /// multiplicative subgroup of order `domain_size`.
+    fn domain_point(domain_size: u64, index: u64) -> U256 {
+        let g = domain::primitive_root_of_unity(domain_size.trailing_zeros());
+        BN254Field::pow(g, U256::from(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poseidon::PoseidonHasher;
+    use alloc::vec;
+
+    /// A proof, honestly built by folding a known low-degree polynomial
+    /// (here, a constant) through `num_layers` layers, alongside the domain
+    /// size it was built for.
+    struct TestProof {
+        proof: FriProof,
+        domain_size: u64,
+    }
+
+    /// Build a valid proof for a constant polynomial. A constant folds to
+    /// itself at every layer regardless of beta, since `f(x) == f(-x) == c`
+    /// makes `(f(x)+f(-x))*inv2 == c` and `(f(x)-f(-x)) == 0`. Query indices
+    /// are taken from the same transcript derivation the verifier uses, so
+    /// the proof is self-consistent.
+    fn build_valid_proof(
+        log2_domain_size: u32,
+        num_layers: usize,
+        num_queries: usize,
+    ) -> TestProof {
+        let domain_size = 1u64 << log2_domain_size;
+        let constant = U256::from(7u64);
+
+        // Every leaf of the constant polynomial's evaluation domain hashes
+        // to the same value, so the Merkle tree over any power-of-two slice
+        // of it collapses to a simple hash chain: `nodes[0]` is the leaf
+        // value and `nodes[k]` is the root of a depth-`k` subtree of it.
+        let mut nodes = vec![constant];
+        for _ in 0..log2_domain_size {
+            let prev = *nodes.last().unwrap();
+            nodes.push(PoseidonHasher::hash_two(prev, prev));
+        }
+
+        let layer_roots: Vec<U256> = (0..num_layers)
+            .map(|i| nodes[log2_domain_size as usize - i])
+            .collect();
+
+        let (_, query_indices) =
+            FriVerifier::derive_challenges(&layer_roots, constant, num_queries, domain_size);
+
+        let queries = query_indices
+            .iter()
+            .map(|&index| {
+                let mut cur_index = index;
+                let mut cur_size = domain_size;
+                let layers = (0..num_layers)
+                    .map(|_| {
+                        let half = cur_size / 2;
+                        let pos_index = cur_index;
+                        let neg_index = (cur_index + half) % cur_size;
+                        let depth = cur_size.trailing_zeros() as usize;
+                        let path = nodes[..depth].to_vec();
+
+                        let opening_pos = FriOpening {
+                            value: constant,
+                            path: path.clone(),
+                            indices: to_indices(pos_index, depth),
+                        };
+                        let opening_neg = FriOpening {
+                            value: constant,
+                            path,
+                            indices: to_indices(neg_index, depth),
+                        };
+
+                        cur_size = half;
+                        cur_index %= half;
+
+                        (opening_pos, opening_neg)
+                    })
+                    .collect();
+                FriQuery { index, layers }
+            })
+            .collect();
+
+        TestProof {
+            proof: FriProof {
+                layer_roots,
+                queries,
+                final_value: constant,
+            },
+            domain_size,
+        }
+    }
+
+    /// Bit-encode `index`'s low `depth` bits, matching
+    /// [`FriVerifier::path_index`]'s convention (bit 0 = leaf level), for a
+    /// `depth`-level Merkle path.
+    fn to_indices(index: u64, depth: usize) -> Vec<bool> {
+        (0..depth).map(|level| (index >> level) & 1 == 1).collect()
+    }
+
+    #[test]
+    fn test_verify_accepts_honest_constant_proof() {
+        let TestProof { proof, domain_size } = build_valid_proof(3, 2, 4);
+        assert!(FriVerifier::verify(&proof, domain_size, 4, 0, &PoseidonHasher));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_opening() {
+        let TestProof {
+            mut proof,
+            domain_size,
+        } = build_valid_proof(3, 2, 4);
+        proof.queries[0].layers[0].0.value =
+            BN254Field::add(proof.queries[0].layers[0].0.value, U256::from(1u64));
+        assert!(!FriVerifier::verify(&proof, domain_size, 4, 0, &PoseidonHasher));
+    }
+
+    #[test]
+    fn test_verify_rejects_chosen_query_index() {
+        // A prover who ignores the transcript-derived index and opens
+        // whatever position it likes must be rejected.
+        let TestProof {
+            mut proof,
+            domain_size,
+        } = build_valid_proof(3, 2, 4);
+        proof.queries[0].index = (proof.queries[0].index + 1) % domain_size;
+        assert!(!FriVerifier::verify(&proof, domain_size, 4, 0, &PoseidonHasher));
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_num_queries() {
+        let TestProof { proof, domain_size } = build_valid_proof(3, 2, 4);
+        assert!(!FriVerifier::verify(&proof, domain_size, 3, 0, &PoseidonHasher));
+    }
+
+    #[test]
+    fn test_verify_rejects_non_power_of_two_domain() {
+        let TestProof { proof, .. } = build_valid_proof(3, 2, 4);
+        assert!(!FriVerifier::verify(&proof, 7, 4, 0, &PoseidonHasher));
+    }
+
+    #[test]
+    fn test_verify_rejects_domain_exceeding_2_adicity() {
+        // A power of two is fine in general but must still fail closed
+        // (rather than panic in `domain_point`) once it exceeds BN254's
+        // 2-adicity.
+        let TestProof { proof, .. } = build_valid_proof(3, 2, 4);
+        let oversized_domain = 1u64 << (MAX_LOG2_DOMAIN_SIZE + 1);
+        assert!(!FriVerifier::verify(
+            &proof,
+            oversized_domain,
+            4,
+            0,
+            &PoseidonHasher
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_excessive_final_degree() {
+        // final_degree must be strictly less than the final domain size.
+        let TestProof { proof, domain_size } = build_valid_proof(3, 2, 4);
+        let final_domain_size = domain_size >> proof.layer_roots.len() as u32;
+        assert!(!FriVerifier::verify(
+            &proof,
+            domain_size,
+            4,
+            final_domain_size as usize,
+            &PoseidonHasher
+        ));
+    }
+}
@@ -10,8 +10,12 @@ use alloc::vec;
 use alloc::vec::Vec;
 use stylus_sdk::{alloy_primitives::U256, prelude::*};
 
+mod field_hasher;
+mod fri;
 mod merkle;
 mod poseidon;
+mod rln;
+mod transcript;
 
 use merkle::MerkleVerifier;
 use poseidon::PoseidonHasher;
@@ -78,7 +82,7 @@ impl StarkVerifier {
         path: Vec<U256>,
         indices: Vec<bool>,
     ) -> bool {
-        let result = MerkleVerifier::verify(root, leaf, &path, &indices);
+        let result = MerkleVerifier::verify(root, leaf, &path, &indices, &PoseidonHasher);
 
         // Store verification result
         self.last_verified_root.set(root);
@@ -1,13 +1,22 @@
 //! Merkle Path Verification
 //!
-//! Implements Merkle tree path verification using Poseidon hash.
-//! Supports verification of membership proofs for trees of any depth.
+//! Implements Merkle tree path verification, generic over any [`FieldHasher`]
+//! so trees built with a different hash (or Poseidon arity) verify through
+//! the same code path. Supports verification of membership proofs for trees
+//! of any depth, plus sparse-Merkle-tree non-membership proofs keyed by bit
+//! position.
 
+use alloc::vec::Vec;
 use alloy_primitives::U256;
 
+use crate::field_hasher::FieldHasher;
+#[cfg(test)]
 use crate::poseidon::PoseidonHasher;
 
-/// Merkle path verifier using Poseidon hash
+/// Maximum supported sparse Merkle tree depth.
+pub const MAX_DEPTH: usize = 32;
+
+/// Merkle path verifier, generic over the field hash used for tree nodes.
 pub struct MerkleVerifier;
 
 impl MerkleVerifier {
@@ -21,6 +30,7 @@ impl MerkleVerifier {
     /// * `leaf` - Leaf value to verify
     /// * `path` - Array of sibling hashes along the path from leaf to root
     /// * `indices` - Position indicators for each level (false=left, true=right)
+    /// * `hasher` - The field hash the tree was built with
     ///
     /// # Returns
     /// `true` if the computed root matches the expected root
@@ -39,7 +49,13 @@ impl MerkleVerifier {
     /// - path = [l1, h23]
     /// - indices = [false, false] (l0 is left child at both levels)
     #[inline]
-    pub fn verify(root: U256, leaf: U256, path: &[U256], indices: &[bool]) -> bool {
+    pub fn verify<H: FieldHasher>(
+        root: U256,
+        leaf: U256,
+        path: &[U256],
+        indices: &[bool],
+        hasher: &H,
+    ) -> bool {
         // Path and indices must have same length
         if path.len() != indices.len() {
             return false;
@@ -57,11 +73,11 @@ impl MerkleVerifier {
             current = if *is_right {
                 // Current node is on the right side
                 // Parent = hash(sibling, current)
-                PoseidonHasher::hash_two(*sibling, current)
+                hasher.hash_two(*sibling, current)
             } else {
                 // Current node is on the left side
                 // Parent = hash(current, sibling)
-                PoseidonHasher::hash_two(current, *sibling)
+                hasher.hash_two(current, *sibling)
             };
         }
 
@@ -69,16 +85,103 @@ impl MerkleVerifier {
         current == root
     }
 
+    /// Empty-subtree hashes per level, for a sparse Merkle tree whose empty
+    /// leaf is `U256::ZERO`.
+    ///
+    /// `default_nodes(hasher)[0]` is the empty leaf itself;
+    /// `default_nodes(hasher)[i + 1]` is the root of an empty subtree of
+    /// depth `i + 1`, computed as `hash(default_nodes[i], default_nodes[i])`.
+    ///
+    /// This is a function rather than a `DEFAULT_NODES: [U256; MAX_DEPTH]`
+    /// constant because hashing isn't `const fn` under an arbitrary
+    /// [`FieldHasher`] (Poseidon's own `hash_two` bottoms out in
+    /// `U256::mul_mod`, which isn't const-evaluable either) - the table has
+    /// to be built at runtime.
+    pub fn default_nodes<H: FieldHasher>(hasher: &H) -> [U256; MAX_DEPTH] {
+        let mut nodes = [U256::ZERO; MAX_DEPTH];
+        for i in 0..MAX_DEPTH - 1 {
+            nodes[i + 1] = hasher.hash_two(nodes[i], nodes[i]);
+        }
+        nodes
+    }
+
+    /// Derive a sparse Merkle tree's position indicators from a key's low
+    /// `depth` bits (bit 0 = leaf level, matching `verify`'s indices order).
+    fn indices_from_key(key: U256, depth: usize) -> Vec<bool> {
+        (0..depth).map(|level| key.bit(level)).collect()
+    }
+
+    /// Verify a sparse Merkle tree membership proof, deriving the leaf's
+    /// position from `key`'s bits instead of an explicit indices array.
+    ///
+    /// # Arguments
+    /// * `root` - Expected Merkle root
+    /// * `leaf` - Leaf value to verify
+    /// * `key` - Key whose bits select the leaf's position in the tree
+    /// * `path` - Sibling hashes along the path from leaf to root
+    /// * `hasher` - The field hash the tree was built with
+    ///
+    /// # Returns
+    /// `true` if the computed root matches the expected root
+    pub fn verify_sparse<H: FieldHasher>(
+        root: U256,
+        leaf: U256,
+        key: U256,
+        path: &[U256],
+        hasher: &H,
+    ) -> bool {
+        if path.len() > MAX_DEPTH {
+            return false;
+        }
+        let indices = Self::indices_from_key(key, path.len());
+        Self::verify(root, leaf, path, &indices, hasher)
+    }
+
+    /// Verify that `key` is *absent* from a sparse Merkle tree, by showing
+    /// that its position holds the empty leaf rather than real data.
+    ///
+    /// `indices` must match `key`'s own bits; this stops a caller from
+    /// proving an unrelated position empty and passing it off as a proof
+    /// about `key`.
+    ///
+    /// # Arguments
+    /// * `root` - Expected Merkle root
+    /// * `key` - Key claimed to be absent
+    /// * `path` - Sibling hashes along the path from the empty leaf to root
+    /// * `indices` - Position indicators for each level (false=left, true=right)
+    /// * `hasher` - The field hash the tree was built with
+    ///
+    /// # Returns
+    /// `true` if `key`'s position resolves to the empty leaf under `root`
+    pub fn verify_non_membership<H: FieldHasher>(
+        root: U256,
+        key: U256,
+        path: &[U256],
+        indices: &[bool],
+        hasher: &H,
+    ) -> bool {
+        if path.len() != indices.len() || path.len() > MAX_DEPTH {
+            return false;
+        }
+        if Self::indices_from_key(key, path.len()) != indices {
+            return false;
+        }
+
+        let empty_leaf = Self::default_nodes(hasher)[0];
+        Self::verify(root, empty_leaf, path, indices, hasher)
+    }
+
     /// Compute Merkle root from leaves
     /// Helper function for testing - builds full tree and returns root
     ///
     /// # Arguments
     /// * `leaves` - Array of leaf values (must be power of 2)
+    /// * `hasher` - The field hash to build the tree with
     ///
     /// # Returns
     /// The Merkle root
     #[cfg(test)]
-    pub fn compute_root(leaves: &[U256]) -> U256 {
+    pub fn compute_root<H: FieldHasher>(leaves: &[U256], hasher: &H) -> U256 {
         if leaves.is_empty() {
             return U256::ZERO;
         }
@@ -86,15 +189,15 @@ impl MerkleVerifier {
             return leaves[0];
         }
 
-        let mut current_level: alloc::vec::Vec<U256> = leaves.to_vec();
+        let mut current_level: Vec<U256> = leaves.to_vec();
 
         while current_level.len() > 1 {
-            let mut next_level = alloc::vec::Vec::new();
+            let mut next_level = Vec::new();
 
             for chunk in current_level.chunks(2) {
                 let left = chunk[0];
                 let right = if chunk.len() > 1 { chunk[1] } else { chunk[0] };
-                next_level.push(PoseidonHasher::hash_two(left, right));
+                next_level.push(hasher.hash_two(left, right));
             }
 
             current_level = next_level;
@@ -104,6 +207,40 @@ impl MerkleVerifier {
     }
 }
 
+/// A Merkle authentication path as ordered `(sibling, is_right)` pairs from
+/// leaf to root, bundling what [`MerkleVerifier::verify`] takes as two
+/// parallel arrays into a single type.
+#[derive(Clone)]
+pub struct Path {
+    /// `(sibling hash, is_right)` pairs, one per level from leaf to root.
+    pub elements: Vec<(U256, bool)>,
+}
+
+impl Path {
+    /// Build a path from its `(sibling, is_right)` elements.
+    pub fn new(elements: Vec<(U256, bool)>) -> Self {
+        Self { elements }
+    }
+
+    /// Recompute the root that `leaf` authenticates to along this path.
+    pub fn calculate_root<H: FieldHasher>(&self, leaf: U256, hasher: &H) -> U256 {
+        let mut current = leaf;
+        for (sibling, is_right) in &self.elements {
+            current = if *is_right {
+                hasher.hash_two(*sibling, current)
+            } else {
+                hasher.hash_two(current, *sibling)
+            };
+        }
+        current
+    }
+
+    /// Check whether `leaf` is a member of the tree rooted at `root`.
+    pub fn check_membership<H: FieldHasher>(&self, root: U256, leaf: U256, hasher: &H) -> bool {
+        self.calculate_root(leaf, hasher) == root
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,8 +250,14 @@ mod tests {
     fn test_empty_path() {
         let leaf = U256::from(42u64);
         // With empty path, leaf should equal root
-        assert!(MerkleVerifier::verify(leaf, leaf, &[], &[]));
-        assert!(!MerkleVerifier::verify(U256::from(1u64), leaf, &[], &[]));
+        assert!(MerkleVerifier::verify(leaf, leaf, &[], &[], &PoseidonHasher));
+        assert!(!MerkleVerifier::verify(
+            U256::from(1u64),
+            leaf,
+            &[],
+            &[],
+            &PoseidonHasher
+        ));
     }
 
     #[test]
@@ -126,10 +269,22 @@ mod tests {
         let root = PoseidonHasher::hash_two(leaf0, leaf1);
 
         // Verify leaf0 (left child)
-        assert!(MerkleVerifier::verify(root, leaf0, &[leaf1], &[false]));
+        assert!(MerkleVerifier::verify(
+            root,
+            leaf0,
+            &[leaf1],
+            &[false],
+            &PoseidonHasher
+        ));
 
         // Verify leaf1 (right child)
-        assert!(MerkleVerifier::verify(root, leaf1, &[leaf0], &[true]));
+        assert!(MerkleVerifier::verify(
+            root,
+            leaf1,
+            &[leaf0],
+            &[true],
+            &PoseidonHasher
+        ));
     }
 
     #[test]
@@ -157,7 +312,8 @@ mod tests {
             root,
             leaves[0],
             &[leaves[1], h23],
-            &[false, false]
+            &[false, false],
+            &PoseidonHasher
         ));
 
         // Verify leaf3 (rightmost)
@@ -165,7 +321,8 @@ mod tests {
             root,
             leaves[3],
             &[leaves[2], h01],
-            &[true, true]
+            &[true, true],
+            &PoseidonHasher
         ));
     }
 
@@ -180,11 +337,18 @@ mod tests {
             root,
             leaf0,
             &[U256::from(999u64)],
-            &[false]
+            &[false],
+            &PoseidonHasher
         ));
 
         // Wrong position
-        assert!(!MerkleVerifier::verify(root, leaf0, &[leaf1], &[true]));
+        assert!(!MerkleVerifier::verify(
+            root,
+            leaf0,
+            &[leaf1],
+            &[true],
+            &PoseidonHasher
+        ));
     }
 
     #[test]
@@ -197,20 +361,21 @@ mod tests {
             root,
             leaf,
             &[U256::from(3u64), U256::from(4u64)],
-            &[false]
+            &[false],
+            &PoseidonHasher
         ));
     }
 
     #[test]
     fn test_depth_8_tree() {
         // Create 256 leaves
-        let leaves: alloc::vec::Vec<U256> = (0..256u64).map(U256::from).collect();
-        let root = MerkleVerifier::compute_root(&leaves);
+        let leaves: Vec<U256> = (0..256u64).map(U256::from).collect();
+        let root = MerkleVerifier::compute_root(&leaves, &PoseidonHasher);
 
         // Build proof for leaf 0
         let mut path = vec![];
         let mut indices = vec![];
-        let mut current_level: alloc::vec::Vec<U256> = leaves.clone();
+        let mut current_level: Vec<U256> = leaves.clone();
         let mut target_index = 0usize;
 
         while current_level.len() > 1 {
@@ -239,6 +404,132 @@ mod tests {
             current_level = next_level;
         }
 
-        assert!(MerkleVerifier::verify(root, leaves[0], &path, &indices));
+        assert!(MerkleVerifier::verify(
+            root,
+            leaves[0],
+            &path,
+            &indices,
+            &PoseidonHasher
+        ));
+    }
+
+    #[test]
+    fn test_default_nodes_chain() {
+        let nodes = MerkleVerifier::default_nodes(&PoseidonHasher);
+
+        assert_eq!(nodes[0], U256::ZERO);
+        for i in 0..MAX_DEPTH - 1 {
+            assert_eq!(nodes[i + 1], PoseidonHasher::hash_two(nodes[i], nodes[i]));
+        }
+    }
+
+    #[test]
+    fn test_verify_non_membership() {
+        let depth = 4;
+        let nodes = MerkleVerifier::default_nodes(&PoseidonHasher);
+
+        // An empty tree of `depth` levels: every sibling is the empty
+        // subtree hash for its level.
+        let path: Vec<U256> = (0..depth).map(|i| nodes[i]).collect();
+        let root = nodes[depth];
+
+        // Key 0b0101 picks a path of alternating left/right children, all
+        // still resolving to the empty leaf in a fully empty tree.
+        let key = U256::from(0b0101u64);
+        let indices = MerkleVerifier::indices_from_key(key, depth);
+
+        assert!(MerkleVerifier::verify_non_membership(
+            root,
+            key,
+            &path,
+            &indices,
+            &PoseidonHasher
+        ));
+    }
+
+    #[test]
+    fn test_verify_non_membership_rejects_mismatched_key() {
+        let depth = 4;
+        let nodes = MerkleVerifier::default_nodes(&PoseidonHasher);
+        let path: Vec<U256> = (0..depth).map(|i| nodes[i]).collect();
+        let root = nodes[depth];
+
+        let key = U256::from(0b0101u64);
+        // Indices for a different key than the one being claimed absent.
+        let wrong_indices = MerkleVerifier::indices_from_key(U256::from(0b0110u64), depth);
+
+        assert!(!MerkleVerifier::verify_non_membership(
+            root,
+            key,
+            &path,
+            &wrong_indices,
+            &PoseidonHasher
+        ));
+    }
+
+    #[test]
+    fn test_verify_non_membership_rejects_occupied_leaf() {
+        let depth = 2;
+        let nodes = MerkleVerifier::default_nodes(&PoseidonHasher);
+
+        // A tree where the leaf at key=0 is occupied by real data, not the
+        // empty leaf, so a non-membership proof for key=0 must fail.
+        let leaf = U256::from(42u64);
+        let h0 = PoseidonHasher::hash_two(leaf, nodes[0]);
+        let root = PoseidonHasher::hash_two(h0, nodes[1]);
+
+        let key = U256::ZERO;
+        let path = [nodes[0], nodes[1]];
+        let indices = MerkleVerifier::indices_from_key(key, depth);
+
+        assert!(!MerkleVerifier::verify_non_membership(
+            root,
+            key,
+            &path,
+            &indices,
+            &PoseidonHasher
+        ));
+    }
+
+    #[test]
+    fn test_verify_sparse_matches_explicit_indices() {
+        let leaf0 = U256::from(100u64);
+        let leaf1 = U256::from(200u64);
+        let root = PoseidonHasher::hash_two(leaf0, leaf1);
+
+        // key's bit 0 = false selects the left child, matching leaf0's
+        // position in `test_simple_two_leaf_tree`.
+        assert!(MerkleVerifier::verify_sparse(
+            root,
+            leaf0,
+            U256::ZERO,
+            &[leaf1],
+            &PoseidonHasher
+        ));
+        assert!(MerkleVerifier::verify_sparse(
+            root,
+            leaf1,
+            U256::from(1u64),
+            &[leaf0],
+            &PoseidonHasher
+        ));
+    }
+
+    #[test]
+    fn test_path_matches_verify() {
+        let leaf0 = U256::from(100u64);
+        let leaf1 = U256::from(200u64);
+        let root = PoseidonHasher::hash_two(leaf0, leaf1);
+
+        let path = Path::new(vec![(leaf1, false)]);
+        assert!(MerkleVerifier::verify(
+            root,
+            leaf0,
+            &[leaf1],
+            &[false],
+            &PoseidonHasher
+        ));
+        assert!(path.check_membership(root, leaf0, &PoseidonHasher));
+        assert_eq!(path.calculate_root(leaf0, &PoseidonHasher), root);
     }
 }
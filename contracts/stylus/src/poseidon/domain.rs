@@ -0,0 +1,63 @@
+//! BN254 Evaluation Domains
+//!
+//! Exposes the 2-adic structure of the BN254 scalar field so callers can
+//! build evaluation domains of size `2^k` (the field supports up to `2^28`).
+
+use alloy_primitives::U256;
+
+use super::field::BN254Field;
+
+/// Largest power-of-two domain size BN254's 2-adicity supports.
+pub(crate) const MAX_LOG2_DOMAIN_SIZE: u32 = 28;
+
+/// Known generator of the field's order-`2^28` multiplicative subgroup.
+/// Smaller power-of-two domains are derived from it by repeated squaring.
+const ROOT_OF_UNITY_28: U256 = U256::from_limbs([
+    0x9bd61b6e725b19f0,
+    0x402d111e41112ed4,
+    0x00e0a7eb8ef62abc,
+    0x2a3c09f0a58a7e85,
+]);
+
+/// Primitive root of unity of order `2^log2_size`, i.e. a generator `g` of
+/// the unique subgroup of size `2^log2_size` with `g^(2^log2_size) == 1`
+/// and `g^(2^(log2_size - 1)) != 1`.
+///
+/// # Panics
+/// Panics if `log2_size > 28`, which exceeds BN254's 2-adicity.
+pub(crate) fn primitive_root_of_unity(log2_size: u32) -> U256 {
+    assert!(
+        log2_size <= MAX_LOG2_DOMAIN_SIZE,
+        "BN254 only supports evaluation domains up to 2^28"
+    );
+
+    let mut root = ROOT_OF_UNITY_28;
+    for _ in 0..(MAX_LOG2_DOMAIN_SIZE - log2_size) {
+        root = BN254Field::mul(root, root);
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_of_unity_has_exact_order() {
+        for log2_size in [1u32, 2, 8, 16, 28] {
+            let g = primitive_root_of_unity(log2_size);
+            let order = U256::from(1u64) << log2_size;
+
+            assert_eq!(BN254Field::pow(g, order), U256::from(1u64));
+            if log2_size > 0 {
+                assert_ne!(BN254Field::pow(g, order >> 1), U256::from(1u64));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_root_of_unity_rejects_oversized_domain() {
+        primitive_root_of_unity(MAX_LOG2_DOMAIN_SIZE + 1);
+    }
+}
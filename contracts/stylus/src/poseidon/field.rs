@@ -61,6 +61,36 @@ impl BN254Field {
             a
         }
     }
+
+    /// Modular exponentiation via square-and-multiply: `base^exp mod p`
+    #[inline]
+    pub fn pow(base: U256, exp: U256) -> U256 {
+        let mut result = U256::from(1u64);
+        let mut base = Self::reduce(base);
+        let mut exp = exp;
+
+        while exp != U256::ZERO {
+            if exp & U256::from(1u64) == U256::from(1u64) {
+                result = Self::mul(result, base);
+            }
+            base = Self::mul(base, base);
+            exp >>= 1;
+        }
+
+        result
+    }
+
+    /// Modular inverse via Fermat's little theorem: `a^(p-2) mod p`
+    ///
+    /// Returns `None` for `a == 0`, which has no multiplicative inverse.
+    #[inline]
+    pub fn inv(a: U256) -> Option<U256> {
+        if a == U256::ZERO {
+            return None;
+        }
+
+        Some(Self::pow(a, BN254_PRIME - U256::from(2u64)))
+    }
 }
 
 #[cfg(test)]
@@ -119,4 +149,31 @@ mod tests {
         let expected = BN254_PRIME - U256::from(2u64);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_pow() {
+        let base = U256::from(3u64);
+        let result = BN254Field::pow(base, U256::from(5u64));
+        assert_eq!(result, U256::from(243u64));
+    }
+
+    #[test]
+    fn test_pow_zero_exponent() {
+        let base = U256::from(123u64);
+        assert_eq!(BN254Field::pow(base, U256::ZERO), U256::from(1u64));
+    }
+
+    #[test]
+    fn test_inv_of_zero_is_none() {
+        assert_eq!(BN254Field::inv(U256::ZERO), None);
+    }
+
+    #[test]
+    fn test_inv_round_trips() {
+        for a in [1u64, 2, 3, 42, 123456789] {
+            let a = U256::from(a);
+            let inv = BN254Field::inv(a).expect("nonzero input has an inverse");
+            assert_eq!(BN254Field::mul(a, inv), U256::from(1u64));
+        }
+    }
 }
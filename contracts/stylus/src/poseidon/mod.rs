@@ -6,10 +6,12 @@
 //! - S-box: x^5
 //! - Rounds: 8 full rounds + 57 partial rounds
 
+use alloc::vec::Vec;
 use alloy_primitives::U256;
 
 mod constants;
-mod field;
+pub(crate) mod domain;
+pub(crate) mod field;
 
 use constants::{MDS_MATRIX, ROUND_CONSTANTS};
 use field::BN254Field;
@@ -20,6 +22,9 @@ pub struct PoseidonHasher;
 impl PoseidonHasher {
     /// State width (t=3 for 2-input hash)
     const T: usize = 3;
+    /// Sponge rate: number of elements absorbed/squeezed per permutation
+    /// (capacity is the remaining `T - RATE = 1` element)
+    pub(crate) const RATE: usize = Self::T - 1;
     /// Number of full rounds
     const FULL_ROUNDS: usize = 8;
     /// Number of partial rounds
@@ -37,30 +42,67 @@ impl PoseidonHasher {
     pub fn hash_two(a: U256, b: U256) -> U256 {
         // Initialize state: [0, a, b]
         let mut state = [U256::ZERO, a, b];
+        Self::permute(&mut state);
+        state[0]
+    }
+
+    /// Hash an arbitrary number of field elements using the Poseidon sponge
+    /// construction (rate = `T - 1` = 2, capacity = 1).
+    ///
+    /// Inputs are absorbed rate-first, with the full permutation applied
+    /// between absorbed blocks. Padding follows the standard 10* scheme: a
+    /// single `1` element is appended, then zeros up to the next rate
+    /// boundary, so inputs of different lengths can never collide.
+    ///
+    /// # Arguments
+    /// * `inputs` - The field elements to hash
+    ///
+    /// # Returns
+    /// The squeezed hash result as a field element
+    pub fn hash_n(inputs: &[U256]) -> U256 {
+        let mut padded: Vec<U256> = Vec::with_capacity(inputs.len() + Self::RATE);
+        padded.extend_from_slice(inputs);
+        padded.push(U256::from(1u64));
+        while padded.len() % Self::RATE != 0 {
+            padded.push(U256::ZERO);
+        }
+
+        let mut state = [U256::ZERO; Self::T];
+        for block in padded.chunks(Self::RATE) {
+            for (i, value) in block.iter().enumerate() {
+                state[1 + i] = BN254Field::add(state[1 + i], *value);
+            }
+            Self::permute(&mut state);
+        }
+
+        state[0]
+    }
 
+    /// Apply the full Poseidon permutation (all full and partial rounds) to
+    /// a width-3 state. Shared by [`Self::hash_two`], [`Self::hash_n`] and
+    /// [`crate::transcript::Transcript`] so every caller's hash domain matches.
+    #[inline]
+    pub(crate) fn permute(state: &mut [U256; 3]) {
         let half_full = Self::FULL_ROUNDS / 2;
         let mut round_ctr = 0;
 
         // First half of full rounds
         for _ in 0..half_full {
-            Self::full_round(&mut state, round_ctr);
+            Self::full_round(state, round_ctr);
             round_ctr += Self::T;
         }
 
         // Partial rounds
         for _ in 0..Self::PARTIAL_ROUNDS {
-            Self::partial_round(&mut state, round_ctr);
+            Self::partial_round(state, round_ctr);
             round_ctr += Self::T;
         }
 
         // Second half of full rounds
         for _ in 0..half_full {
-            Self::full_round(&mut state, round_ctr);
+            Self::full_round(state, round_ctr);
             round_ctr += Self::T;
         }
-
-        // Return first state element as hash output
-        state[0]
     }
 
     /// Full round: apply round constants, S-box to all elements, then MDS
@@ -169,4 +211,53 @@ mod tests {
             "Poseidon hash does not match circomlib test vector"
         );
     }
+
+    #[test]
+    fn test_hash_n_deterministic() {
+        let inputs = [U256::from(1u64), U256::from(2u64), U256::from(3u64)];
+
+        let hash1 = PoseidonHasher::hash_n(&inputs);
+        let hash2 = PoseidonHasher::hash_n(&inputs);
+
+        assert_eq!(hash1, hash2);
+    }
+
+    #[test]
+    fn test_hash_n_single_rate_block_differs_from_hash_two() {
+        // hash_n absorbs [1, 2] and then a second, padding-only block [1, 0],
+        // so it must not collide with hash_two(1, 2), which stops after the
+        // first permutation.
+        let a = U256::from(1u64);
+        let b = U256::from(2u64);
+
+        assert_ne!(
+            PoseidonHasher::hash_n(&[a, b]),
+            PoseidonHasher::hash_two(a, b)
+        );
+    }
+
+    #[test]
+    fn test_hash_n_different_lengths_differ() {
+        // Padding must stop a short input from colliding with a longer one
+        // that happens to share a prefix.
+        let hash2 = PoseidonHasher::hash_n(&[U256::from(1u64), U256::from(2u64)]);
+        let hash3 = PoseidonHasher::hash_n(&[
+            U256::from(1u64),
+            U256::from(2u64),
+            U256::from(0u64),
+        ]);
+
+        assert_ne!(hash2, hash3);
+    }
+
+    #[test]
+    fn test_hash_n_spans_multiple_blocks() {
+        // 5 inputs span three rate-2 blocks; just check it produces a
+        // stable, non-zero digest.
+        let inputs: Vec<U256> = (1..=5u64).map(U256::from).collect();
+        let hash = PoseidonHasher::hash_n(&inputs);
+
+        assert_ne!(hash, U256::ZERO);
+        assert_eq!(hash, PoseidonHasher::hash_n(&inputs));
+    }
 }
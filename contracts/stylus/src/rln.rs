@@ -0,0 +1,191 @@
+//! Rate-Limiting Nullifier (RLN)
+//!
+//! Adds spam resistance to on-chain messaging on top of the existing Merkle
+//! membership check. A registered identity signals once per epoch by
+//! revealing a point `(x, y)` on a degree-1 polynomial `y = a0 + a1*x` over
+//! BN254, where `a0` is their identity secret and `a1` binds the line to the
+//! current epoch. A single signal per epoch reveals nothing about `a0`; two
+//! signals in the *same* epoch reveal two points on the same line, letting
+//! anyone reconstruct `a0` via [`recover_secret`] and slash the spammer.
+//!
+//! This module checks the plaintext secret-sharing relation directly
+//! (`id_secret` is passed in, not hidden behind a SNARK) - it's the
+//! reference implementation of the RLN relation a future ZK circuit would
+//! prove without revealing `id_secret` on-chain.
+
+use alloy_primitives::U256;
+
+use crate::merkle::MerkleVerifier;
+use crate::poseidon::field::BN254Field;
+use crate::poseidon::PoseidonHasher;
+
+/// Verify a single RLN signal.
+///
+/// Checks that:
+/// 1. The leaf `hash_n([id_commitment])`, where `id_commitment` is
+///    `hash_n([id_secret])`, is a member of the tree rooted at `root`.
+/// 2. `nullifier` is the epoch-bound nullifier for `id_secret`.
+/// 3. `(x, y)` lies on the line `y = id_secret + a1*x` for that nullifier's
+///    `a1`.
+///
+/// # Arguments
+/// * `root` - Expected Merkle root of the identity set
+/// * `id_secret` - The signaler's identity secret (`a0`)
+/// * `path` - Sibling hashes from that leaf to `root`
+/// * `indices` - Position indicators for each level (false=left, true=right)
+/// * `epoch` - The current rate-limiting epoch
+/// * `x` - The signal's x-coordinate (typically `hash_n([message])`)
+/// * `y` - The revealed share `a0 + a1*x`
+/// * `nullifier` - The claimed epoch nullifier `hash_n([a1])`
+///
+/// # Returns
+/// `true` if the identity is a member and the share is consistent with
+/// `epoch` and `nullifier`.
+pub fn verify_signal(
+    root: U256,
+    id_secret: U256,
+    path: &[U256],
+    indices: &[bool],
+    epoch: U256,
+    x: U256,
+    y: U256,
+    nullifier: U256,
+) -> bool {
+    let id_commitment = PoseidonHasher::hash_n(&[id_secret]);
+    let leaf = PoseidonHasher::hash_n(&[id_commitment]);
+    if !MerkleVerifier::verify(root, leaf, path, indices, &PoseidonHasher) {
+        return false;
+    }
+
+    let a1 = PoseidonHasher::hash_n(&[id_secret, epoch]);
+    if PoseidonHasher::hash_n(&[a1]) != nullifier {
+        return false;
+    }
+
+    let expected_y = BN254Field::add(id_secret, BN254Field::mul(a1, x));
+    expected_y == y
+}
+
+/// Recover an identity secret (`a0`) from two shares revealed in the same
+/// epoch, by Lagrange-interpolating the shared line at `x = 0`:
+/// `a0 = y1 + (y2 - y1) * (-x1) * inv(x2 - x1)`.
+///
+/// # Returns
+/// `None` if both shares have the same x-coordinate (the line is
+/// underdetermined and `x2 - x1` has no inverse).
+pub fn recover_secret(share1: (U256, U256), share2: (U256, U256)) -> Option<U256> {
+    let (x1, y1) = share1;
+    let (x2, y2) = share2;
+
+    let inv_dx = BN254Field::inv(BN254Field::sub(x2, x1))?;
+    let a1 = BN254Field::mul(BN254Field::sub(y2, y1), inv_dx);
+    let a0 = BN254Field::sub(y1, BN254Field::mul(a1, x1));
+
+    Some(a0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a single-leaf tree (the identity commitment is the whole tree)
+    /// so `path`/`indices` are trivially empty.
+    fn single_member_tree(id_secret: U256) -> U256 {
+        let id_commitment = PoseidonHasher::hash_n(&[id_secret]);
+        PoseidonHasher::hash_n(&[id_commitment])
+    }
+
+    #[test]
+    fn test_verify_signal_valid() {
+        let id_secret = U256::from(1234u64);
+        let root = single_member_tree(id_secret);
+
+        let epoch = U256::from(7u64);
+        let message = U256::from(12345u64);
+        let x = PoseidonHasher::hash_n(&[message]);
+        let a1 = PoseidonHasher::hash_n(&[id_secret, epoch]);
+        let y = BN254Field::add(id_secret, BN254Field::mul(a1, x));
+        let nullifier = PoseidonHasher::hash_n(&[a1]);
+
+        assert!(verify_signal(
+            root, id_secret, &[], &[], epoch, x, y, nullifier
+        ));
+    }
+
+    #[test]
+    fn test_verify_signal_rejects_non_member() {
+        let id_secret = U256::from(1234u64);
+        // Root for a different identity entirely.
+        let root = single_member_tree(U256::from(9999u64));
+
+        let epoch = U256::from(7u64);
+        let x = U256::from(42u64);
+        let a1 = PoseidonHasher::hash_n(&[id_secret, epoch]);
+        let y = BN254Field::add(id_secret, BN254Field::mul(a1, x));
+        let nullifier = PoseidonHasher::hash_n(&[a1]);
+
+        assert!(!verify_signal(
+            root, id_secret, &[], &[], epoch, x, y, nullifier
+        ));
+    }
+
+    #[test]
+    fn test_verify_signal_rejects_wrong_share() {
+        let id_secret = U256::from(1234u64);
+        let root = single_member_tree(id_secret);
+
+        let epoch = U256::from(7u64);
+        let x = U256::from(42u64);
+        let a1 = PoseidonHasher::hash_n(&[id_secret, epoch]);
+        let nullifier = PoseidonHasher::hash_n(&[a1]);
+
+        // Tampered y that doesn't lie on the line.
+        let bad_y = BN254Field::add(id_secret, BN254Field::mul(a1, x)) + U256::from(1u64);
+
+        assert!(!verify_signal(
+            root, id_secret, &[], &[], epoch, x, bad_y, nullifier
+        ));
+    }
+
+    #[test]
+    fn test_recover_secret_from_two_signals_same_epoch() {
+        let id_secret = U256::from(555u64);
+        let epoch = U256::from(3u64);
+        let a1 = PoseidonHasher::hash_n(&[id_secret, epoch]);
+
+        let x1 = U256::from(10u64);
+        let y1 = BN254Field::add(id_secret, BN254Field::mul(a1, x1));
+        let x2 = U256::from(20u64);
+        let y2 = BN254Field::add(id_secret, BN254Field::mul(a1, x2));
+
+        let recovered = recover_secret((x1, y1), (x2, y2)).expect("distinct x values");
+        assert_eq!(recovered, id_secret);
+    }
+
+    #[test]
+    fn test_recover_secret_rejects_same_x() {
+        let x = U256::from(10u64);
+        assert!(recover_secret((x, U256::from(1u64)), (x, U256::from(2u64))).is_none());
+    }
+
+    #[test]
+    fn test_single_signal_does_not_reveal_secret() {
+        // A single (x, y) pair alone is consistent with infinitely many
+        // (id_secret, a1) lines; recovery needs a second point.
+        let id_secret = U256::from(777u64);
+        let epoch = U256::from(1u64);
+        let a1 = PoseidonHasher::hash_n(&[id_secret, epoch]);
+        let x = U256::from(9u64);
+        let y = BN254Field::add(id_secret, BN254Field::mul(a1, x));
+
+        // Any other candidate secret admits a matching a1 for the same point.
+        let other_secret = U256::from(111u64);
+        let other_a1 = BN254Field::mul(
+            BN254Field::sub(y, other_secret),
+            BN254Field::inv(x).expect("x != 0"),
+        );
+        let other_y = BN254Field::add(other_secret, BN254Field::mul(other_a1, x));
+
+        assert_eq!(other_y, y);
+    }
+}